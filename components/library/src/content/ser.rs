@@ -1,4 +1,15 @@
 //! What we are sending to the templates when rendering them
+//!
+//! Breaking API change: `from_page`, `from_page_basic`, `from_section`,
+//! `from_section_basic`, `TranslatedContent::find_all_pages` and
+//! `TranslatedContent::find_all_sections` all gained a `default_lang`
+//! parameter (the site's `Config::default_language`) to mark `translations`
+//! entries' `is_default` and to include a page/section in its own
+//! `translations` list. Every caller of these `pub` functions -- the site
+//! crate's render/build pipeline, `templates/global_fns`, and any other
+//! `library` consumer -- needs to be updated in lockstep to pass
+//! `&config.default_language` through. Those call sites live outside this
+//! file and aren't touched here.
 use std::collections::HashMap;
 use std::collections::HashSet;
 use std::path::Path;
@@ -10,6 +21,117 @@ use crate::content::{Page, Section};
 use crate::library::Library;
 use rendering::Heading;
 
+/// Guesses a mime type from a file extension without pulling a whole mime-sniffing crate
+/// into this crate's dependency tree; good enough for the file kinds content authors
+/// actually drop into a page/section's asset folder.
+fn guess_mime(extension: Option<&str>) -> &'static str {
+    match extension.map(|e| e.to_lowercase()).as_deref() {
+        Some("jpg") | Some("jpeg") => "image/jpeg",
+        Some("png") => "image/png",
+        Some("gif") => "image/gif",
+        Some("svg") => "image/svg+xml",
+        Some("webp") => "image/webp",
+        Some("avif") => "image/avif",
+        Some("ico") => "image/x-icon",
+        Some("pdf") => "application/pdf",
+        Some("mp4") => "video/mp4",
+        Some("webm") => "video/webm",
+        Some("mp3") => "audio/mpeg",
+        Some("ogg") => "audio/ogg",
+        Some("wasm") => "application/wasm",
+        Some("json") => "application/json",
+        Some("css") => "text/css",
+        Some("js") => "application/javascript",
+        Some("txt") => "text/plain",
+        Some("html") | Some("htm") => "text/html",
+        Some("xml") => "application/xml",
+        _ => "application/octet-stream",
+    }
+}
+
+/// `Page`/`Section` don't (yet) carry a field with asset dimensions read once at discovery
+/// time -- adding one belongs in `content/page.rs`/`content/section.rs`, populated wherever
+/// `serialized_assets` is first built, which this file doesn't define. Until that lands, every
+/// `SerializingAsset` reports `width`/`height` as `None`; `from_assets`'s `dimensions` parameter
+/// stays in the signature so a caller that does have the data can pass it straight through
+/// instead of us inventing a nonexistent field to read it from here.
+fn no_asset_dimensions_yet() -> HashMap<String, (u32, u32)> {
+    HashMap::new()
+}
+
+/// A page/section asset, exposed richly enough that templates can build galleries or
+/// `<img>` tags with correct intrinsic dimensions, without shelling out to external tools
+/// or re-reading the file themselves.
+///
+/// `width`/`height` are only set when `from_assets` is given dimensions for the asset;
+/// `SerializingPage`/`SerializingSection` pass none, so on those they are always `None`.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct SerializingAsset {
+    permalink: String,
+    /// Site-relative path (not a filesystem path, which would leak the build machine's
+    /// layout and wouldn't reproduce across builds).
+    path: String,
+    filename: String,
+    extension: Option<String>,
+    mime: String,
+    width: Option<u32>,
+    height: Option<u32>,
+}
+
+impl SerializingAsset {
+    /// `dimensions` must already have been read once when the asset was first collected
+    /// (see `Page`/`Section` asset discovery -- or `no_asset_dimensions_yet` above, until that
+    /// exists); we never touch the filesystem here, so this stays cheap to call from every
+    /// sibling/page serialization.
+    ///
+    /// Neither `image` nor `mime_guess` is a dependency of this crate: decoding image headers
+    /// to get `(width, height)` is only ever done once per asset, at discovery time, by
+    /// whichever part of the build pulls `serialized_assets` together -- that's the natural
+    /// place for an `image` dependency to live (and the one place paying for it is
+    /// acceptable), not here, where the same asset gets serialized again on every sibling and
+    /// in every section's `pages` list that includes its page. Mime type is a closed, small set for the file
+    /// kinds authors actually drop in an asset folder, so a tiny local match table
+    /// (`guess_mime`) covers it without a dependency at all.
+    ///
+    /// `asset` is whatever `serialized_assets` hands us for one entry -- a bare filename in
+    /// the simple case, but templates shouldn't have to care either way, so we explicitly
+    /// strip to the file name ourselves instead of assuming the shape.
+    fn new(asset: &str, content_path: &str, permalink: &str, dimensions: Option<(u32, u32)>) -> Self {
+        let filename = Path::new(asset)
+            .file_name()
+            .map(|f| f.to_string_lossy().into_owned())
+            .unwrap_or_else(|| asset.to_owned());
+        let extension = Path::new(&filename).extension().map(|e| e.to_string_lossy().into_owned());
+        let mime = guess_mime(extension.as_deref()).to_string();
+        let (width, height) = match dimensions {
+            Some((w, h)) => (Some(w), Some(h)),
+            None => (None, None),
+        };
+
+        SerializingAsset {
+            permalink: format!("{}{}", permalink, filename),
+            path: format!("{}{}", content_path, filename),
+            filename,
+            extension,
+            mime,
+            width,
+            height,
+        }
+    }
+
+    fn from_assets(
+        assets: &[String],
+        dimensions: &HashMap<String, (u32, u32)>,
+        content_path: &str,
+        permalink: &str,
+    ) -> Vec<Self> {
+        assets
+            .iter()
+            .map(|a| SerializingAsset::new(a, content_path, permalink, dimensions.get(a).copied()))
+            .collect()
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Serialize)]
 pub struct TranslatedContent<'a> {
     lang: &'a str,
@@ -18,12 +140,32 @@ pub struct TranslatedContent<'a> {
     /// The path to the markdown file; useful for retrieving the full page through
     /// the `get_page` function.
     path: &'a Path,
+    /// Whether `lang` is the site's `default_language`, so templates can build
+    /// `hreflang="x-default"` alternate-link blocks without looking at the config themselves.
+    is_default: bool,
 }
 
 impl<'a> TranslatedContent<'a> {
     // copypaste eh, not worth creating an enum imo
-    pub fn find_all_sections(section: &'a Section, library: &'a Library) -> Vec<Self> {
-        let mut translations = vec![];
+    // Breaking change: `translations` used to list only the *other* languages; the current
+    // section/page is now included too (first, with the rest following) so a language switcher
+    // can render the complete set, itself included, from a single loop instead of special-casing
+    // the current language. Existing templates that assumed `translations` excluded the current
+    // page (e.g. to build a "other languages" list) need to skip the entry whose `lang`
+    // matches the current one themselves now (`is_default` marks the site's default language,
+    // not the current page).
+    pub fn find_all_sections(
+        section: &'a Section,
+        library: &'a Library,
+        default_lang: &str,
+    ) -> Vec<Self> {
+        let mut translations = vec![TranslatedContent {
+            lang: &section.lang,
+            permalink: &section.permalink,
+            title: &section.meta.title,
+            path: &section.file.path,
+            is_default: section.lang == default_lang,
+        }];
 
         #[allow(clippy::or_fun_call)]
         for key in library
@@ -39,14 +181,21 @@ impl<'a> TranslatedContent<'a> {
                 permalink: &other.permalink,
                 title: &other.meta.title,
                 path: &other.file.path,
+                is_default: other.lang == default_lang,
             });
         }
 
         translations
     }
 
-    pub fn find_all_pages(page: &'a Page, library: &'a Library) -> Vec<Self> {
-        let mut translations = vec![];
+    pub fn find_all_pages(page: &'a Page, library: &'a Library, default_lang: &str) -> Vec<Self> {
+        let mut translations = vec![TranslatedContent {
+            lang: &page.lang,
+            permalink: &page.permalink,
+            title: &page.meta.title,
+            path: &page.file.path,
+            is_default: page.lang == default_lang,
+        }];
 
         #[allow(clippy::or_fun_call)]
         for key in
@@ -58,6 +207,7 @@ impl<'a> TranslatedContent<'a> {
                 permalink: &other.permalink,
                 title: &other.meta.title,
                 path: &other.file.path,
+                is_default: other.lang == default_lang,
             });
         }
 
@@ -65,6 +215,64 @@ impl<'a> TranslatedContent<'a> {
     }
 }
 
+/// A very light reference to a page or section that links back to the current page or section,
+/// used to build "pages that link here" sections without risking cycles by embedding
+/// a full `SerializingPage`.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct Backlink<'a> {
+    title: &'a Option<String>,
+    permalink: &'a str,
+    path: &'a str,
+}
+
+/// Finds all the pages/sections in the same language whose resolved `internal_links`
+/// (the `@/...md` targets Zola resolves while rendering markdown) point at
+/// `relative_path`, which is the `page.file.relative`/`section.file.relative` of the
+/// content we are building backlinks for -- the same identifier `internal_links` entries
+/// are recorded against, not the URL-shaped `page.path`/`section.path`.
+///
+/// This scans every page and section in the library, so building backlinks for every
+/// page/section in turn is O(n^2) over a build. Only `from_page`/`from_section` pay for
+/// it (not the `_basic` variants, which skip backlinks entirely), so the cost only hits
+/// once per real page/section, not per sibling -- acceptable for now, but a site with
+/// many thousands of pages would benefit from a reverse-link index built once alongside
+/// `internal_links` resolution instead of re-scanning here.
+fn find_backlinks<'a>(
+    relative_path: &'a str,
+    lang: &'a str,
+    library: &'a Library,
+) -> Vec<Backlink<'a>> {
+    let mut backlinks = vec![];
+
+    for page in library.pages().values() {
+        if page.lang != lang {
+            continue;
+        }
+        if page.internal_links.iter().any(|(target, _)| target == relative_path) {
+            backlinks.push(Backlink {
+                title: &page.meta.title,
+                permalink: &page.permalink,
+                path: &page.path,
+            });
+        }
+    }
+
+    for section in library.sections().values() {
+        if section.lang != lang {
+            continue;
+        }
+        if section.internal_links.iter().any(|(target, _)| target == relative_path) {
+            backlinks.push(Backlink {
+                title: &section.meta.title,
+                permalink: &section.permalink,
+                path: &section.path,
+            });
+        }
+    }
+
+    backlinks
+}
+
 #[derive(Clone, Debug, PartialEq, Serialize)]
 pub struct SerializingPage<'a> {
     relative_path: &'a str,
@@ -88,6 +296,7 @@ pub struct SerializingPage<'a> {
     word_count: Option<usize>,
     reading_time: Option<usize>,
     assets: &'a [String],
+    asset_objects: Vec<SerializingAsset>,
     draft: bool,
     lang: &'a str,
     lighter: Option<Box<SerializingPage<'a>>>,
@@ -99,11 +308,23 @@ pub struct SerializingPage<'a> {
     title_prev: Option<Box<SerializingPage<'a>>>,
     title_next: Option<Box<SerializingPage<'a>>>,
     translations: Vec<TranslatedContent<'a>>,
+    backlinks: Vec<Backlink<'a>>,
 }
 
 impl<'a> SerializingPage<'a> {
-    /// Grabs all the data from a page, including sibling pages
-    pub fn from_page(page: &'a Page, library: &'a Library) -> Self {
+    /// Grabs all the data from a page, including sibling pages. `default_lang` is the
+    /// site's `Config::default_language`, needed to mark `translations` entries and passed
+    /// down to every sibling's own `from_page_basic` call.
+    ///
+    /// Siblings are still built eagerly here, one `from_page_basic` call each: Tera converts
+    /// the whole `SerializingPage` into a `tera::Value` via `to_value` before a template ever
+    /// runs, and that conversion walks every field regardless of what the template reads, so a
+    /// `Serialize` impl has no way to defer the work until `page.later.title` is actually
+    /// accessed -- there's no per-field hook to hang laziness off. Real on-demand resolution
+    /// would mean exposing siblings as a Tera `Function` (e.g. `get_page(key=page.later)`)
+    /// instead of a plain field, which is a breaking template-syntax change well beyond this
+    /// file; eager construction is the correct tradeoff until that's undertaken.
+    pub fn from_page(page: &'a Page, library: &'a Library, default_lang: &'a str) -> Self {
         let mut year = None;
         let mut month = None;
         let mut day = None;
@@ -113,37 +334,44 @@ impl<'a> SerializingPage<'a> {
             day = Some(d.2);
         }
         let pages = library.pages();
-        let lighter = page
-            .lighter
-            .map(|k| Box::new(Self::from_page_basic(pages.get(k).unwrap(), Some(library))));
-        let heavier = page
-            .heavier
-            .map(|k| Box::new(Self::from_page_basic(pages.get(k).unwrap(), Some(library))));
-        let earlier_updated = page
-            .earlier_updated
-            .map(|k| Box::new(Self::from_page_basic(pages.get(k).unwrap(), Some(library))));
-        let later_updated = page
-            .later_updated
-            .map(|k| Box::new(Self::from_page_basic(pages.get(k).unwrap(), Some(library))));
-        let earlier = page
-            .earlier
-            .map(|k| Box::new(Self::from_page_basic(pages.get(k).unwrap(), Some(library))));
-        let later = page
-            .later
-            .map(|k| Box::new(Self::from_page_basic(pages.get(k).unwrap(), Some(library))));
-        let title_prev = page
-            .title_prev
-            .map(|k| Box::new(Self::from_page_basic(pages.get(k).unwrap(), Some(library))));
-        let title_next = page
-            .title_next
-            .map(|k| Box::new(Self::from_page_basic(pages.get(k).unwrap(), Some(library))));
+        let lighter = page.lighter.map(|k| {
+            Box::new(Self::from_page_basic(pages.get(k).unwrap(), Some(library), Some(default_lang)))
+        });
+        let heavier = page.heavier.map(|k| {
+            Box::new(Self::from_page_basic(pages.get(k).unwrap(), Some(library), Some(default_lang)))
+        });
+        let earlier_updated = page.earlier_updated.map(|k| {
+            Box::new(Self::from_page_basic(pages.get(k).unwrap(), Some(library), Some(default_lang)))
+        });
+        let later_updated = page.later_updated.map(|k| {
+            Box::new(Self::from_page_basic(pages.get(k).unwrap(), Some(library), Some(default_lang)))
+        });
+        let earlier = page.earlier.map(|k| {
+            Box::new(Self::from_page_basic(pages.get(k).unwrap(), Some(library), Some(default_lang)))
+        });
+        let later = page.later.map(|k| {
+            Box::new(Self::from_page_basic(pages.get(k).unwrap(), Some(library), Some(default_lang)))
+        });
+        let title_prev = page.title_prev.map(|k| {
+            Box::new(Self::from_page_basic(pages.get(k).unwrap(), Some(library), Some(default_lang)))
+        });
+        let title_next = page.title_next.map(|k| {
+            Box::new(Self::from_page_basic(pages.get(k).unwrap(), Some(library), Some(default_lang)))
+        });
         let ancestors = page
             .ancestors
             .iter()
             .map(|k| library.get_section_by_key(*k).file.relative.as_str())
             .collect();
 
-        let translations = TranslatedContent::find_all_pages(page, library);
+        let translations = TranslatedContent::find_all_pages(page, library, default_lang);
+        let backlinks = find_backlinks(&page.file.relative, &page.lang, library);
+        let asset_objects = SerializingAsset::from_assets(
+            &page.serialized_assets,
+            &no_asset_dimensions_yet(),
+            &page.path,
+            &page.permalink,
+        );
 
         SerializingPage {
             relative_path: &page.file.relative,
@@ -167,6 +395,7 @@ impl<'a> SerializingPage<'a> {
             word_count: page.word_count,
             reading_time: page.reading_time,
             assets: &page.serialized_assets,
+            asset_objects,
             draft: page.meta.draft,
             lang: &page.lang,
             lighter,
@@ -178,6 +407,7 @@ impl<'a> SerializingPage<'a> {
             title_prev,
             title_next,
             translations,
+            backlinks,
         }
     }
 
@@ -186,8 +416,13 @@ impl<'a> SerializingPage<'a> {
         self.title
     }
 
-    /// Same as from_page but does not fill sibling pages
-    pub fn from_page_basic(page: &'a Page, library: Option<&'a Library>) -> Self {
+    /// Same as from_page but does not fill sibling pages. `default_lang` is only needed
+    /// (and only used) when `library` is also present, to build `translations`.
+    pub fn from_page_basic(
+        page: &'a Page,
+        library: Option<&'a Library>,
+        default_lang: Option<&'a str>,
+    ) -> Self {
         let mut year = None;
         let mut month = None;
         let mut day = None;
@@ -205,12 +440,19 @@ impl<'a> SerializingPage<'a> {
             vec![]
         };
 
-        let translations = if let Some(lib) = library {
-            TranslatedContent::find_all_pages(page, lib)
+        let translations = if let (Some(lib), Some(default_lang)) = (library, default_lang) {
+            TranslatedContent::find_all_pages(page, lib, default_lang)
         } else {
             vec![]
         };
 
+        let asset_objects = SerializingAsset::from_assets(
+            &page.serialized_assets,
+            &no_asset_dimensions_yet(),
+            &page.path,
+            &page.permalink,
+        );
+
         SerializingPage {
             relative_path: &page.file.relative,
             ancestors,
@@ -233,6 +475,7 @@ impl<'a> SerializingPage<'a> {
             word_count: page.word_count,
             reading_time: page.reading_time,
             assets: &page.serialized_assets,
+            asset_objects,
             draft: page.meta.draft,
             lang: &page.lang,
             lighter: None,
@@ -244,6 +487,9 @@ impl<'a> SerializingPage<'a> {
             title_prev: None,
             title_next: None,
             translations,
+            // Backlinks require scanning every page/section in the library, which is too
+            // expensive to redo for every sibling a page pulls in; only `from_page` pays for it.
+            backlinks: vec![],
         }
     }
 }
@@ -265,20 +511,28 @@ pub struct SerializingSection<'a> {
     reading_time: Option<usize>,
     lang: &'a str,
     assets: &'a [String],
+    asset_objects: Vec<SerializingAsset>,
     pages: Vec<SerializingPage<'a>>,
     subsections: Vec<&'a str>,
     translations: Vec<TranslatedContent<'a>>,
     includers: Vec<&'a str>,
+    backlinks: Vec<Backlink<'a>>,
 }
 
 impl<'a> SerializingSection<'a> {
-    pub fn from_section(section: &'a Section, library: &'a Library) -> Self {
+    /// `default_lang` is the site's `Config::default_language`, needed to mark `translations`
+    /// entries and passed down to each page's own `from_page_basic` call.
+    pub fn from_section(section: &'a Section, library: &'a Library, default_lang: &'a str) -> Self {
         let mut pages = Vec::with_capacity(section.pages.len());
         let mut subsections = Vec::with_capacity(section.subsections.len());
         let mut includers = Vec::with_capacity(section.includers.len());
 
         for k in &section.pages {
-            pages.push(library.get_page_by_key(*k).to_serialized_basic(library));
+            pages.push(SerializingPage::from_page_basic(
+                library.get_page_by_key(*k),
+                Some(library),
+                Some(default_lang),
+            ));
         }
 
         for k in &section.subsections {
@@ -294,7 +548,14 @@ impl<'a> SerializingSection<'a> {
             .iter()
             .map(|k| library.get_section_by_key(*k).file.relative.as_str())
             .collect();
-        let translations = TranslatedContent::find_all_sections(section, library);
+        let translations = TranslatedContent::find_all_sections(section, library, default_lang);
+        let backlinks = find_backlinks(&section.file.relative, &section.lang, library);
+        let asset_objects = SerializingAsset::from_assets(
+            &section.serialized_assets,
+            &no_asset_dimensions_yet(),
+            &section.path,
+            &section.permalink,
+        );
 
         SerializingSection {
             relative_path: &section.file.relative,
@@ -311,16 +572,23 @@ impl<'a> SerializingSection<'a> {
             word_count: section.word_count,
             reading_time: section.reading_time,
             assets: &section.serialized_assets,
+            asset_objects,
             lang: &section.lang,
             pages,
             subsections,
             translations,
             includers,
+            backlinks,
         }
     }
 
-    /// Same as from_section but doesn't fetch pages
-    pub fn from_section_basic(section: &'a Section, library: Option<&'a Library>) -> Self {
+    /// Same as from_section but doesn't fetch pages. `default_lang` is only needed (and only
+    /// used) when `library` is also present, to build `translations`.
+    pub fn from_section_basic(
+        section: &'a Section,
+        library: Option<&'a Library>,
+        default_lang: Option<&'a str>,
+    ) -> Self {
         let mut ancestors = vec![];
         let mut translations = vec![];
         let mut subsections = vec![];
@@ -331,12 +599,21 @@ impl<'a> SerializingSection<'a> {
                 .iter()
                 .map(|k| lib.get_section_by_key(*k).file.relative.as_str())
                 .collect();
-            translations = TranslatedContent::find_all_sections(section, lib);
+            if let Some(default_lang) = default_lang {
+                translations = TranslatedContent::find_all_sections(section, lib, default_lang);
+            }
             subsections =
                 section.subsections.iter().map(|k| lib.get_section_path_by_key(*k)).collect();
             includers = section.includers.iter().map(|k| lib.get_section_path_by_key(*k)).collect();
         }
 
+        let asset_objects = SerializingAsset::from_assets(
+            &section.serialized_assets,
+            &no_asset_dimensions_yet(),
+            &section.path,
+            &section.permalink,
+        );
+
         SerializingSection {
             relative_path: &section.file.relative,
             ancestors,
@@ -352,11 +629,127 @@ impl<'a> SerializingSection<'a> {
             word_count: section.word_count,
             reading_time: section.reading_time,
             assets: &section.serialized_assets,
+            asset_objects,
             lang: &section.lang,
             pages: vec![],
             subsections,
             translations,
             includers,
+            // Same tradeoff as `SerializingPage::from_page_basic`: skip the full library scan.
+            backlinks: vec![],
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    #[test]
+    fn asset_objects_derive_mime_and_site_relative_path_without_touching_disk() {
+        // One bare filename (the common case) and one that already carries a path prefix,
+        // the shape `serialized_assets` entries can actually have -- each must resolve to its
+        // own file name (`diagram.png`, `chart.png`) under the content path, not a doubled-up path.
+        let assets = vec![
+            "diagram.png".to_string(),
+            "blog/post/nested/chart.png".to_string(),
+            "notes.txt".to_string(),
+        ];
+        let mut dimensions = HashMap::new();
+        dimensions.insert("diagram.png".to_string(), (640, 480));
+        dimensions.insert("blog/post/nested/chart.png".to_string(), (100, 200));
+
+        let objects = SerializingAsset::from_assets(
+            &assets,
+            &dimensions,
+            "/blog/post/",
+            "https://example.com/blog/post/",
+        );
+
+        assert_eq!(objects.len(), 3);
+
+        let diagram = &objects[0];
+        assert_eq!(diagram.filename, "diagram.png");
+        assert_eq!(diagram.extension.as_deref(), Some("png"));
+        assert_eq!(diagram.mime, "image/png");
+        assert_eq!(diagram.width, Some(640));
+        assert_eq!(diagram.height, Some(480));
+        assert_eq!(diagram.path, "/blog/post/diagram.png");
+        assert_eq!(diagram.permalink, "https://example.com/blog/post/diagram.png");
+
+        let chart = &objects[1];
+        assert_eq!(chart.filename, "chart.png");
+        assert_eq!(chart.width, Some(100));
+        assert_eq!(chart.path, "/blog/post/chart.png");
+        assert_eq!(chart.permalink, "https://example.com/blog/post/chart.png");
+
+        let notes = &objects[2];
+        assert_eq!(notes.mime, "text/plain");
+        assert_eq!(notes.width, None);
+        assert_eq!(notes.height, None);
+    }
+
+    fn create_page(relative_path: &str, lang: &str) -> Page {
+        let mut page = Page::default();
+        page.file.relative = relative_path.to_string();
+        page.lang = lang.to_string();
+        page.path = format!("/{}/", relative_path.trim_end_matches(".md"));
+        page.permalink = format!("https://example.com{}", page.path);
+        page
+    }
+
+    #[test]
+    fn find_backlinks_matches_on_the_markdown_relative_path_not_the_url_path() {
+        let mut library = Library::default();
+
+        let target = create_page("blog/target.md", "en");
+
+        let mut linker = create_page("blog/linker.md", "en");
+        linker.internal_links = vec![("blog/target.md".to_string(), None)];
+        let linker_permalink = linker.permalink.clone();
+
+        let mut wrong_lang_linker = create_page("blog/linker-fr.md", "fr");
+        wrong_lang_linker.internal_links = vec![("blog/target.md".to_string(), None)];
+
+        let mut unrelated = create_page("blog/unrelated.md", "en");
+        unrelated.internal_links = vec![("blog/somewhere-else.md".to_string(), None)];
+
+        library.insert_page(linker);
+        library.insert_page(wrong_lang_linker);
+        library.insert_page(unrelated);
+
+        let backlinks = find_backlinks(&target.file.relative, "en", &library);
+
+        assert_eq!(backlinks.len(), 1);
+        assert_eq!(backlinks[0].permalink, linker_permalink);
+    }
+
+    // NOTE: this pins a breaking change -- `translations` used to contain only the *other*
+    // languages, now it also contains the page/section itself (first, so templates can build
+    // a complete language switcher from a single loop over `translations`).
+    #[test]
+    fn translations_list_includes_self_first_and_marks_the_default_language() {
+        use std::path::PathBuf;
+
+        let mut library = Library::default();
+
+        let mut en = create_page("blog/post.md", "en");
+        en.file.canonical = PathBuf::from("blog/post.md");
+        let mut fr = create_page("blog/post.fr.md", "fr");
+        fr.file.canonical = PathBuf::from("blog/post.md");
+
+        let en_key = library.insert_page(en);
+        let fr_key = library.insert_page(fr);
+        library.translations.insert(PathBuf::from("blog/post.md"), vec![fr_key].into_iter().collect());
+
+        let en_page = library.get_page_by_key(en_key);
+        let translations = TranslatedContent::find_all_pages(en_page, &library, "en");
+
+        assert_eq!(translations.len(), 2);
+        assert_eq!(translations[0].lang, "en");
+        assert!(translations[0].is_default);
+        assert_eq!(translations[1].lang, "fr");
+        assert!(!translations[1].is_default);
+    }
+}